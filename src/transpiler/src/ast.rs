@@ -0,0 +1,61 @@
+use crate::lexer::{Span, TokenType};
+
+/// A function parameter: just a name, since the parser reads no type
+/// annotation on parameters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Param {
+    pub(crate) name: String,
+    pub(crate) span: Span,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Expr {
+    Integer { text: String, span: Span },
+    Float { text: String, span: Span },
+    String { text: String, span: Span },
+    Identifier { name: String, span: Span },
+    Unary {
+        op: TokenType,
+        operand: Box<Expr>,
+        span: Span,
+    },
+    Binary {
+        left: Box<Expr>,
+        op: TokenType,
+        right: Box<Expr>,
+        span: Span,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Stmt {
+    Let {
+        name: String,
+        value: Expr,
+        span: Span,
+    },
+    Const {
+        name: String,
+        value: Expr,
+        span: Span,
+    },
+    Function {
+        name: String,
+        params: Vec<Param>,
+        return_type: Option<String>,
+        body: Vec<Stmt>,
+        span: Span,
+    },
+    Return {
+        value: Option<Expr>,
+        span: Span,
+    },
+    Import {
+        path: String,
+        span: Span,
+    },
+    Expression {
+        expr: Expr,
+        span: Span,
+    },
+}
@@ -1,4 +1,5 @@
-enum TokenType {
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum TokenType {
     /* Key Words */
     Function,
     Return,
@@ -9,6 +10,7 @@ enum TokenType {
     /* Literals */
     String,
     Integer,
+    Float,
 
     /* Identifier */
     Identifier,
@@ -24,31 +26,591 @@ enum TokenType {
     /* Separators */
     Period,
     Comma,
-    Set,    // := or = (still can't decide)
+    Set,    // := (assignment)
     Colon,
     Semicolon,
     Arrow,
 
     /* Operators */
-    // NOTE Just the simple ones for now!
     Plus,
     Minus,
+    Star,
+    Slash,
+    Bang,
+    BangEqual,
+    Equal,  // = (also still used as assignment until the grammar settles)
+    EqualEqual,
+    Less,
+    LessEqual,
+    Greater,
+    GreaterEqual,
+
+    /* End of input */
+    Eof,
+}
+
+/// Looks up `text` against the reserved keywords, falling back to
+/// `Identifier` when it is not one.
+fn keyword_or_identifier(text: &str) -> TokenType {
+    match text {
+        "function" => TokenType::Function,
+        "return" => TokenType::Return,
+        "import" => TokenType::Import,
+        "let" => TokenType::Let,
+        "const" => TokenType::Const,
+        _ => TokenType::Identifier,
+    }
+}
+
+/// The source range a token was scanned from, as a half-open `[start, end)`
+/// offset range plus the 1-based line/column of its first character.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Span {
+    pub(crate) start: u32,
+    pub(crate) end: u32,
+    pub(crate) line: u32,
+    pub(crate) column: u32,
+}
+
+#[derive(Debug, PartialEq)]
+pub(crate) struct Token {
+    pub(crate) kind: TokenType,
+    pub(crate) text: String,
+    pub(crate) span: Span,
+}
+
+/// What went wrong while scanning a single lexeme.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum LexErrorKind {
+    UnexpectedCharacter(char),
+    UnterminatedString,
+}
+
+/// Decodes the character following a `\` inside a string literal, returning
+/// `None` for an escape sequence this lexer doesn't recognize (in which case
+/// the backslash and the character are kept as-is).
+fn decode_escape(c: char) -> Option<char> {
+    match c {
+        'n' => Some('\n'),
+        't' => Some('\t'),
+        '"' => Some('"'),
+        '\\' => Some('\\'),
+        _ => None,
+    }
 }
 
-struct Token {
-    type: TokenType,
-    text: String,
-    position: u32,
+/// A lexical error, pointing at the source range that caused it so the
+/// transpiler can render `expected ':' at line 4, col 12`-style diagnostics.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct LexError {
+    pub(crate) kind: LexErrorKind,
+    pub(crate) span: Span,
 }
 
-struct Lexer {
-    tokens: Vec<Token>,
+/// Namespace for the scanning entry points (`run`, `relex`); it holds no
+/// state of its own, since a pass's state lives in the `Cursor` it drives.
+pub(crate) struct Lexer;
+
+/// A cursor over the input characters, tracking offset, line, and column so
+/// the scanner can look ahead without consuming and can stamp each token
+/// with its source position.
+struct Cursor {
+    chars: Vec<char>,
+    index: usize,
+    line: u32,
+    column: u32,
+}
+
+impl Cursor {
+    fn new(input_text: &str) -> Self {
+        Cursor {
+            chars: input_text.chars().collect(),
+            index: 0,
+            line: 1,
+            column: 1,
+        }
+    }
+
+    /// Builds a cursor already positioned at `index`, replaying the
+    /// characters before it to recover the correct line/column. Used by
+    /// incremental relexing to restart scanning partway through the text.
+    fn seek(chars: Vec<char>, index: usize) -> Self {
+        let mut line = 1;
+        let mut column = 1;
+        for &c in &chars[..index.min(chars.len())] {
+            if c == '\n' {
+                line += 1;
+                column = 1;
+            } else {
+                column += 1;
+            }
+        }
+        Cursor { chars, index, line, column }
+    }
+
+    fn is_at_end(&self) -> bool {
+        self.index >= self.chars.len()
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.index).copied()
+    }
+
+    fn peek_next(&self) -> Option<char> {
+        self.chars.get(self.index + 1).copied()
+    }
+
+    fn advance(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.index += 1;
+        if c == '\n' {
+            self.line += 1;
+            self.column = 1;
+        } else {
+            self.column += 1;
+        }
+        Some(c)
+    }
+
+    /// Snapshots the current offset/line/column as the start of a new token.
+    fn mark(&self) -> Span {
+        Span {
+            start: self.index as u32,
+            end: self.index as u32,
+            line: self.line,
+            column: self.column,
+        }
+    }
+
+    /// Closes a span opened with `mark`, filling in the end offset.
+    fn close(&self, start: Span) -> Span {
+        Span {
+            end: self.index as u32,
+            ..start
+        }
+    }
+
+    /// Implements maximal munch for two-character operators: if the next
+    /// character is `second`, consumes it and returns `two`; otherwise
+    /// leaves the cursor untouched and returns `one`.
+    fn munch(&mut self, second: char, two: TokenType, one: TokenType) -> TokenType {
+        if self.peek() == Some(second) {
+            self.advance();
+            two
+        } else {
+            one
+        }
+    }
 }
 
 impl Lexer {
-    fn run(input_text: String) {
-        for (i, c) in input_text.chars().enumerate() {
-            println!("{}: {}", i, c);
+    pub(crate) fn run(input_text: String) -> Result<Vec<Token>, Vec<LexError>> {
+        let mut cursor = Cursor::new(&input_text);
+        let mut errors = Vec::new();
+        let mut tokens = Vec::new();
+
+        while let Some(token) = Self::scan_one(&mut cursor, &mut errors) {
+            tokens.push(token);
+        }
+        tokens.push(Token { kind: TokenType::Eof, text: String::new(), span: cursor.mark() });
+
+        if errors.is_empty() {
+            Ok(tokens)
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Scans the next lexeme, skipping whitespace and recording recoverable
+    /// errors along the way. Returns `None` once the cursor reaches the end
+    /// of input, leaving the caller to append the `Eof` token.
+    fn scan_one(cursor: &mut Cursor, errors: &mut Vec<LexError>) -> Option<Token> {
+        loop {
+            if cursor.is_at_end() {
+                return None;
+            }
+            let start = cursor.mark();
+            let c = cursor.advance().expect("checked by is_at_end");
+
+            if c.is_whitespace() {
+                continue;
+            }
+
+            if c.is_alphabetic() || c == '_' {
+                let mut text = String::from(c);
+                while let Some(next) = cursor.peek() {
+                    if next.is_alphanumeric() || next == '_' {
+                        text.push(next);
+                        cursor.advance();
+                    } else {
+                        break;
+                    }
+                }
+                let kind = keyword_or_identifier(&text);
+                return Some(Token { kind, text, span: cursor.close(start) });
+            }
+
+            if c.is_ascii_digit() {
+                let mut text = String::from(c);
+                let mut kind = TokenType::Integer;
+                while let Some(next) = cursor.peek() {
+                    if next.is_ascii_digit() {
+                        text.push(next);
+                        cursor.advance();
+                    } else {
+                        break;
+                    }
+                }
+                // Only consume the `.` as a fractional separator if it is
+                // followed by a digit; otherwise it's a `Period` token, as
+                // in `1.foo` or a bare trailing `1.`.
+                if cursor.peek() == Some('.') && cursor.peek_next().is_some_and(|d| d.is_ascii_digit()) {
+                    text.push(cursor.advance().expect("peeked '.'"));
+                    while let Some(next) = cursor.peek() {
+                        if next.is_ascii_digit() {
+                            text.push(next);
+                            cursor.advance();
+                        } else {
+                            break;
+                        }
+                    }
+                    kind = TokenType::Float;
+                }
+                return Some(Token { kind, text, span: cursor.close(start) });
+            }
+
+            if c == '"' {
+                let mut text = String::new();
+                let mut terminated = false;
+                loop {
+                    match cursor.peek() {
+                        Some('"') => {
+                            cursor.advance();
+                            terminated = true;
+                            break;
+                        }
+                        Some('\n') | None => break,
+                        Some('\\') => {
+                            cursor.advance();
+                            match cursor.advance() {
+                                Some(escaped) => match decode_escape(escaped) {
+                                    Some(decoded) => text.push(decoded),
+                                    None => {
+                                        text.push('\\');
+                                        text.push(escaped);
+                                    }
+                                },
+                                None => break,
+                            }
+                        }
+                        Some(next) => {
+                            text.push(next);
+                            cursor.advance();
+                        }
+                    }
+                }
+                if !terminated {
+                    errors.push(LexError {
+                        kind: LexErrorKind::UnterminatedString,
+                        span: cursor.close(start),
+                    });
+                    continue;
+                }
+                return Some(Token { kind: TokenType::String, text, span: cursor.close(start) });
+            }
+
+            let kind = match c {
+                '(' => TokenType::LeftParenthesis,
+                ')' => TokenType::RightParenthesis,
+                '[' => TokenType::LeftSquareBracket,
+                ']' => TokenType::RightSquareBracket,
+                '{' => TokenType::LeftBrace,
+                '}' => TokenType::RightBrace,
+                '.' => TokenType::Period,
+                ',' => TokenType::Comma,
+                ':' => cursor.munch('=', TokenType::Set, TokenType::Colon),
+                ';' => TokenType::Semicolon,
+                '+' => TokenType::Plus,
+                '-' => cursor.munch('>', TokenType::Arrow, TokenType::Minus),
+                '*' => TokenType::Star,
+                '/' => TokenType::Slash,
+                '!' => cursor.munch('=', TokenType::BangEqual, TokenType::Bang),
+                '=' => cursor.munch('=', TokenType::EqualEqual, TokenType::Equal),
+                '<' => cursor.munch('=', TokenType::LessEqual, TokenType::Less),
+                '>' => cursor.munch('=', TokenType::GreaterEqual, TokenType::Greater),
+                _ => {
+                    errors.push(LexError {
+                        kind: LexErrorKind::UnexpectedCharacter(c),
+                        span: cursor.close(start),
+                    });
+                    continue;
+                }
+            };
+            let span = cursor.close(start);
+            let text: String = cursor.chars[start.start as usize..span.end as usize].iter().collect();
+            return Some(Token { kind, text, span });
         }
     }
+
+    /// Re-lexes only the part of `input_text` (the text *after* the edit has
+    /// already been applied) affected by an edit spanning `[edit_start,
+    /// edit_start + removed_len)` in the previous text, where `inserted_len`
+    /// characters were put in its place.
+    ///
+    /// Rescans forward from a safe point before the edit and splices the
+    /// unaffected suffix of `previous` back in (offsets shifted by the
+    /// edit's length delta) as soon as a freshly scanned token lines up with
+    /// one from the old stream, instead of rescanning the whole file.
+    pub(crate) fn relex(
+        previous: &[Token],
+        input_text: &str,
+        edit_start: u32,
+        removed_len: u32,
+        inserted_len: u32,
+    ) -> Result<Vec<Token>, Vec<LexError>> {
+        let delta: i64 = inserted_len as i64 - removed_len as i64;
+
+        // Back up one token past whatever overlaps the edit, since a token
+        // just before it could change meaning by merging with the edited
+        // text (e.g. `/` growing into `//`, or a string swallowing what
+        // follows it).
+        let overlap_index = previous
+            .iter()
+            .position(|token| token.span.end > edit_start)
+            .unwrap_or_else(|| previous.len().saturating_sub(1));
+        let restart_index = overlap_index.saturating_sub(1);
+        let restart_offset = previous.get(restart_index).map_or(0, |token| token.span.start);
+
+        let chars: Vec<char> = input_text.chars().collect();
+        let mut cursor = Cursor::seek(chars, restart_offset as usize);
+        let mut errors = Vec::new();
+        // Everything before the restart point is entirely before the edit,
+        // so it carries over untouched: same offsets, same line/column.
+        let mut tokens: Vec<Token> = previous[..restart_index]
+            .iter()
+            .map(|token| Token { kind: token.kind, text: token.text.clone(), span: token.span })
+            .collect();
+        let mut resume_from = None;
+
+        while let Some(token) = Self::scan_one(&mut cursor, &mut errors) {
+            // Only tokens entirely past the edited region can possibly line
+            // up with the old stream; compare against the old offset this
+            // token's start would have had before the edit.
+            let resync = if errors.is_empty()
+                && token.span.start as i64 >= edit_start as i64 + inserted_len as i64
+            {
+                let old_start = token.span.start as i64 - delta;
+                previous.iter().position(|old| {
+                    old.kind == token.kind
+                        && old.span.start as i64 == old_start
+                        && old.span.end - old.span.start == token.span.end - token.span.start
+                })
+            } else {
+                None
+            };
+            tokens.push(token);
+            if let Some(old_index) = resync {
+                resume_from = Some(old_index + 1);
+                break;
+            }
+        }
+
+        let mut eof_span = cursor.mark();
+        if let Some(start_index) = resume_from {
+            for old in &previous[start_index..] {
+                let new_start = (old.span.start as i64 + delta) as u32;
+                let new_end = (old.span.end as i64 + delta) as u32;
+
+                // Walk the cursor through the real post-edit characters (the
+                // gap since the last spliced token, then the token itself)
+                // so `line`/`column` reflect this edit rather than the
+                // pre-edit stream, even when the edit added or removed
+                // newlines.
+                while (cursor.index as u32) < new_start && cursor.advance().is_some() {}
+                let line = cursor.line;
+                let column = cursor.column;
+                while (cursor.index as u32) < new_end && cursor.advance().is_some() {}
+
+                if old.kind == TokenType::Eof {
+                    eof_span = Span { start: new_start, end: new_end, line, column };
+                    continue;
+                }
+                tokens.push(Token {
+                    kind: old.kind,
+                    text: old.text.clone(),
+                    span: Span { start: new_start, end: new_end, line, column },
+                });
+            }
+        }
+        tokens.push(Token { kind: TokenType::Eof, text: String::new(), span: eof_span });
+
+        if errors.is_empty() {
+            Ok(tokens)
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn identifier_prefixed_by_a_keyword_is_not_mis_lexed() {
+        // A letter-by-letter match on `let` would stop as soon as it saw
+        // `let` inside `letter` and emit `Let` followed by a stray `ter`
+        // identifier; scanning the whole identifier first avoids that.
+        let tokens = Lexer::run("letter".to_string()).expect("should lex cleanly");
+        assert_eq!(tokens.len(), 2); // `letter`, Eof
+        assert_eq!(tokens[0].kind, TokenType::Identifier);
+        assert_eq!(tokens[0].text, "letter");
+    }
+
+    #[test]
+    fn multiple_bad_characters_are_all_reported_in_one_pass() {
+        let errors = Lexer::run("let x = 1 @ 2 $ 3;".to_string())
+            .expect_err("stray characters should be lex errors");
+        assert_eq!(errors.len(), 2);
+        assert_eq!(errors[0].kind, LexErrorKind::UnexpectedCharacter('@'));
+        assert_eq!(errors[1].kind, LexErrorKind::UnexpectedCharacter('$'));
+    }
+
+    #[test]
+    fn float_lexing_distinguishes_trailing_period_forms() {
+        let tokens = Lexer::run("1. 1.5 1.foo".to_string()).expect("should lex cleanly");
+        let kinds: Vec<TokenType> = tokens.iter().map(|t| t.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                TokenType::Integer,    // `1` (the `.` after it is its own token)
+                TokenType::Period,
+                TokenType::Float,      // `1.5`
+                TokenType::Integer,    // `1` (the `.foo` after it is a member access)
+                TokenType::Period,
+                TokenType::Identifier, // `foo`
+                TokenType::Eof,
+            ]
+        );
+    }
+
+    #[test]
+    fn string_escapes_are_decoded() {
+        let tokens = Lexer::run(r#""a\nb\tc\"d\\e""#.to_string()).expect("should lex cleanly");
+        assert_eq!(tokens[0].kind, TokenType::String);
+        assert_eq!(tokens[0].text, "a\nb\tc\"d\\e");
+    }
+
+    #[test]
+    fn unknown_escape_sequence_is_kept_literally() {
+        let tokens = Lexer::run(r#""a\qb""#.to_string()).expect("should lex cleanly");
+        assert_eq!(tokens[0].text, "a\\qb");
+    }
+
+    #[test]
+    fn unterminated_string_at_eof_is_a_lex_error() {
+        let errors = Lexer::run("\"abc".to_string()).expect_err("unterminated string at EOF");
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].kind, LexErrorKind::UnterminatedString);
+    }
+
+    #[test]
+    fn unterminated_string_at_newline_is_a_lex_error() {
+        // No closing quote before the raw newline: the string ends there
+        // rather than swallowing the rest of the source.
+        let errors =
+            Lexer::run("\"abc\ndef".to_string()).expect_err("unterminated string at newline");
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].kind, LexErrorKind::UnterminatedString);
+    }
+
+    /// Diffs `before`/`after` down to a single `(edit_start, removed_len,
+    /// inserted_len)` edit by stripping their common prefix and suffix, the
+    /// same shape of edit `relex` expects from a caller.
+    fn edit_between(before: &str, after: &str) -> (u32, u32, u32) {
+        let before: Vec<char> = before.chars().collect();
+        let after: Vec<char> = after.chars().collect();
+
+        let mut prefix = 0;
+        while prefix < before.len() && prefix < after.len() && before[prefix] == after[prefix] {
+            prefix += 1;
+        }
+        let mut suffix = 0;
+        while suffix < before.len() - prefix
+            && suffix < after.len() - prefix
+            && before[before.len() - 1 - suffix] == after[after.len() - 1 - suffix]
+        {
+            suffix += 1;
+        }
+
+        let removed_len = (before.len() - prefix - suffix) as u32;
+        let inserted_len = (after.len() - prefix - suffix) as u32;
+        (prefix as u32, removed_len, inserted_len)
+    }
+
+    /// Asserts that incrementally relexing `before` -> `after` produces
+    /// exactly what a full `Lexer::run` over `after` would.
+    fn assert_relex_matches_full_run(before: &str, after: &str) {
+        let previous = Lexer::run(before.to_string())
+            .unwrap_or_else(|errors| panic!("`before` should lex cleanly: {:?}", errors));
+        let (edit_start, removed_len, inserted_len) = edit_between(before, after);
+
+        let incremental = Lexer::relex(&previous, after, edit_start, removed_len, inserted_len);
+        let full = Lexer::run(after.to_string());
+
+        match (incremental, full) {
+            (Ok(incremental_tokens), Ok(full_tokens)) => {
+                assert_eq!(
+                    incremental_tokens, full_tokens,
+                    "relex({:?} -> {:?}) diverged from a full run",
+                    before, after
+                );
+            }
+            (Err(incremental_errors), Err(full_errors)) => {
+                assert_eq!(
+                    incremental_errors.len(),
+                    full_errors.len(),
+                    "relex({:?} -> {:?}) reported a different number of errors than a full run",
+                    before, after
+                );
+            }
+            (incremental, full) => panic!(
+                "relex({:?} -> {:?}) disagreed with a full run on success: {:?} vs {:?}",
+                before,
+                after,
+                incremental.is_ok(),
+                full.is_ok()
+            ),
+        }
+    }
+
+    #[test]
+    fn relex_matches_full_run_for_edit_at_start() {
+        assert_relex_matches_full_run("let x = 1;", "const x = 1;");
+    }
+
+    #[test]
+    fn relex_matches_full_run_for_edit_in_middle() {
+        assert_relex_matches_full_run("let x = 1; let y = 2;", "let x = 100; let y = 2;");
+    }
+
+    #[test]
+    fn relex_matches_full_run_for_edit_at_end() {
+        assert_relex_matches_full_run("let x = 1;", "let x = 12;");
+    }
+
+    #[test]
+    fn relex_matches_full_run_when_token_count_changes() {
+        assert_relex_matches_full_run("let x = 1;", "let x = 1 + 2;");
+    }
+
+    #[test]
+    fn relex_matches_full_run_across_an_inserted_newline() {
+        assert_relex_matches_full_run("let x = 1;\nlet y = 2;", "let x = 1;\n\nlet y = 2;");
+    }
+
+    #[test]
+    fn relex_matches_full_run_when_edit_introduces_unterminated_string() {
+        assert_relex_matches_full_run("let x = 1;", "let x = \"unterminated;");
+    }
 }
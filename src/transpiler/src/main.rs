@@ -1,11 +1,42 @@
+mod ast;
+mod lexer;
+mod parser;
+
+use lexer::Lexer;
+use parser::Parser;
+
 struct Transpiler {
     input_path: String,
 }
 
+impl Transpiler {
+    fn run(&self) {
+        let source = std::fs::read_to_string(&self.input_path).expect("failed to read input file");
+
+        let tokens = match Lexer::run(source) {
+            Ok(tokens) => tokens,
+            Err(errors) => {
+                for error in &errors {
+                    eprintln!("lex error at line {}, col {}", error.span.line, error.span.column);
+                }
+                std::process::exit(1);
+            }
+        };
+
+        match Parser::new(tokens).parse() {
+            Ok(statements) => println!("parsed {} statement(s)", statements.len()),
+            Err(errors) => {
+                for error in &errors {
+                    eprintln!("parse error at line {}, col {}", error.span.line, error.span.column);
+                }
+                std::process::exit(1);
+            }
+        }
+    }
+}
+
 fn main() {
     let input_path = std::env::args().nth(1).expect("expected input path");
-    let transpiler = Transpiler {
-        input_path: input_path,
-    };
-    println!("Parsing {}", transpiler.input_path);
+    let transpiler = Transpiler { input_path };
+    transpiler.run();
 }
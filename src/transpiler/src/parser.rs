@@ -0,0 +1,378 @@
+use crate::ast::{Expr, Param, Stmt};
+use crate::lexer::{Span, Token, TokenType};
+
+/// What went wrong while parsing, distinct from `LexError` since a bad
+/// token stream and a bad grammar are different failure modes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum ParseErrorKind {
+    Expected { what: &'static str, found: TokenType },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ParseError {
+    pub(crate) kind: ParseErrorKind,
+    pub(crate) span: Span,
+}
+
+pub(crate) struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+/// Binding powers for infix operators, used by the Pratt/precedence-climbing
+/// expression parser: the higher the pair, the tighter the operator binds.
+fn infix_binding_power(kind: TokenType) -> Option<(u8, u8)> {
+    match kind {
+        TokenType::EqualEqual | TokenType::BangEqual => Some((1, 2)),
+        TokenType::Less | TokenType::LessEqual | TokenType::Greater | TokenType::GreaterEqual => {
+            Some((3, 4))
+        }
+        TokenType::Plus | TokenType::Minus => Some((5, 6)),
+        TokenType::Star | TokenType::Slash => Some((7, 8)),
+        _ => None,
+    }
+}
+
+impl Parser {
+    pub(crate) fn new(tokens: Vec<Token>) -> Self {
+        Parser { tokens, pos: 0 }
+    }
+
+    pub(crate) fn parse(mut self) -> Result<Vec<Stmt>, Vec<ParseError>> {
+        let mut statements = Vec::new();
+        let mut errors = Vec::new();
+
+        while !self.is_at_end() {
+            match self.parse_statement() {
+                Ok(stmt) => statements.push(stmt),
+                Err(error) => {
+                    errors.push(error);
+                    self.synchronize();
+                }
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(statements)
+        } else {
+            Err(errors)
+        }
+    }
+
+    fn current(&self) -> &Token {
+        &self.tokens[self.pos]
+    }
+
+    fn is_at_end(&self) -> bool {
+        self.current().kind == TokenType::Eof
+    }
+
+    fn advance(&mut self) -> &Token {
+        if !self.is_at_end() {
+            self.pos += 1;
+        }
+        &self.tokens[self.pos.saturating_sub(1)]
+    }
+
+    fn check(&self, kind: TokenType) -> bool {
+        self.current().kind == kind
+    }
+
+    fn match_kind(&mut self, kind: TokenType) -> bool {
+        if self.check(kind) {
+            self.advance();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, kind: TokenType, what: &'static str) -> Result<&Token, ParseError> {
+        if self.check(kind) {
+            Ok(self.advance())
+        } else {
+            Err(ParseError {
+                kind: ParseErrorKind::Expected { what, found: self.current().kind },
+                span: self.current().span,
+            })
+        }
+    }
+
+    /// After a parse error, skips tokens until a likely statement boundary
+    /// so the remaining statements can still be parsed and reported.
+    fn synchronize(&mut self) {
+        while !self.is_at_end() {
+            if self.check(TokenType::Semicolon) {
+                self.advance();
+                return;
+            }
+            match self.current().kind {
+                TokenType::Function
+                | TokenType::Return
+                | TokenType::Import
+                | TokenType::Let
+                | TokenType::Const => return,
+                _ => {
+                    self.advance();
+                }
+            }
+        }
+    }
+
+    fn parse_statement(&mut self) -> Result<Stmt, ParseError> {
+        match self.current().kind {
+            TokenType::Let => self.parse_binding(TokenType::Let),
+            TokenType::Const => self.parse_binding(TokenType::Const),
+            TokenType::Function => self.parse_function(),
+            TokenType::Return => self.parse_return(),
+            TokenType::Import => self.parse_import(),
+            _ => self.parse_expression_statement(),
+        }
+    }
+
+    fn parse_binding(&mut self, keyword: TokenType) -> Result<Stmt, ParseError> {
+        let start = self.advance().span; // `let` or `const`
+        let name = self.expect(TokenType::Identifier, "a binding name")?.text.clone();
+        // The lexer accepts either spelling of assignment (`=` or `:=`)
+        // since the grammar hasn't settled on one; the parser accepts
+        // whichever shows up here rather than picking a side.
+        if !self.match_kind(TokenType::Equal) {
+            self.expect(TokenType::Set, "'=' or ':=' after binding name")?;
+        }
+        let value = self.parse_expr(0)?;
+        let end = self.expect(TokenType::Semicolon, "';' after binding value")?.span;
+        let span = span_between(start, end);
+        Ok(match keyword {
+            TokenType::Let => Stmt::Let { name, value, span },
+            _ => Stmt::Const { name, value, span },
+        })
+    }
+
+    fn parse_function(&mut self) -> Result<Stmt, ParseError> {
+        let start = self.advance().span; // `function`
+        let name = self.expect(TokenType::Identifier, "a function name")?.text.clone();
+        self.expect(TokenType::LeftParenthesis, "'(' after function name")?;
+        let mut params = Vec::new();
+        if !self.check(TokenType::RightParenthesis) {
+            loop {
+                let param = self.expect(TokenType::Identifier, "a parameter name")?;
+                params.push(Param { name: param.text.clone(), span: param.span });
+                if !self.match_kind(TokenType::Comma) {
+                    break;
+                }
+            }
+        }
+        self.expect(TokenType::RightParenthesis, "')' after parameter list")?;
+
+        let return_type = if self.match_kind(TokenType::Arrow) {
+            Some(self.expect(TokenType::Identifier, "a return type after '->'")?.text.clone())
+        } else {
+            None
+        };
+
+        self.expect(TokenType::LeftBrace, "'{' to start the function body")?;
+        let mut body = Vec::new();
+        while !self.check(TokenType::RightBrace) && !self.is_at_end() {
+            body.push(self.parse_statement()?);
+        }
+        let end = self.expect(TokenType::RightBrace, "'}' to close the function body")?.span;
+
+        Ok(Stmt::Function { name, params, return_type, body, span: span_between(start, end) })
+    }
+
+    fn parse_return(&mut self) -> Result<Stmt, ParseError> {
+        let start = self.advance().span; // `return`
+        let value = if self.check(TokenType::Semicolon) {
+            None
+        } else {
+            Some(self.parse_expr(0)?)
+        };
+        let end = self.expect(TokenType::Semicolon, "';' after return value")?.span;
+        Ok(Stmt::Return { value, span: span_between(start, end) })
+    }
+
+    fn parse_import(&mut self) -> Result<Stmt, ParseError> {
+        let start = self.advance().span; // `import`
+        let path = self.expect(TokenType::String, "a module path")?.text.clone();
+        let end = self.expect(TokenType::Semicolon, "';' after import")?.span;
+        Ok(Stmt::Import { path, span: span_between(start, end) })
+    }
+
+    fn parse_expression_statement(&mut self) -> Result<Stmt, ParseError> {
+        let expr = self.parse_expr(0)?;
+        let end = self.expect(TokenType::Semicolon, "';' after expression")?.span;
+        let span = span_between(expr_span(&expr), end);
+        Ok(Stmt::Expression { expr, span })
+    }
+
+    /// Precedence-climbing expression parser: parses a prefix/primary
+    /// expression, then keeps consuming infix operators whose left binding
+    /// power exceeds `min_bp`, recursing on the right-hand side with the
+    /// operator's right binding power as the new minimum.
+    fn parse_expr(&mut self, min_bp: u8) -> Result<Expr, ParseError> {
+        let mut left = self.parse_prefix()?;
+
+        while let Some((left_bp, right_bp)) = infix_binding_power(self.current().kind) {
+            if left_bp < min_bp {
+                break;
+            }
+            let op = self.advance().kind;
+            let right = self.parse_expr(right_bp)?;
+            let span = span_between(expr_span(&left), expr_span(&right));
+            left = Expr::Binary { left: Box::new(left), op, right: Box::new(right), span };
+        }
+
+        Ok(left)
+    }
+
+    fn parse_prefix(&mut self) -> Result<Expr, ParseError> {
+        if self.check(TokenType::Minus) || self.check(TokenType::Bang) {
+            let op_token = self.advance();
+            let op = op_token.kind;
+            let start = op_token.span;
+            let operand = self.parse_prefix()?;
+            let span = span_between(start, expr_span(&operand));
+            return Ok(Expr::Unary { op, operand: Box::new(operand), span });
+        }
+        self.parse_primary()
+    }
+
+    fn parse_primary(&mut self) -> Result<Expr, ParseError> {
+        let token = self.current();
+        match token.kind {
+            TokenType::Integer => {
+                let token = self.advance();
+                Ok(Expr::Integer { text: token.text.clone(), span: token.span })
+            }
+            TokenType::Float => {
+                let token = self.advance();
+                Ok(Expr::Float { text: token.text.clone(), span: token.span })
+            }
+            TokenType::String => {
+                let token = self.advance();
+                Ok(Expr::String { text: token.text.clone(), span: token.span })
+            }
+            TokenType::Identifier => {
+                let token = self.advance();
+                Ok(Expr::Identifier { name: token.text.clone(), span: token.span })
+            }
+            TokenType::LeftParenthesis => {
+                self.advance();
+                let inner = self.parse_expr(0)?;
+                self.expect(TokenType::RightParenthesis, "')' to close grouping")?;
+                Ok(inner)
+            }
+            _ => Err(ParseError {
+                kind: ParseErrorKind::Expected { what: "an expression", found: token.kind },
+                span: token.span,
+            }),
+        }
+    }
+}
+
+fn expr_span(expr: &Expr) -> Span {
+    match expr {
+        Expr::Integer { span, .. }
+        | Expr::Float { span, .. }
+        | Expr::String { span, .. }
+        | Expr::Identifier { span, .. }
+        | Expr::Unary { span, .. }
+        | Expr::Binary { span, .. } => *span,
+    }
+}
+
+fn span_between(start: Span, end: Span) -> Span {
+    Span { start: start.start, end: end.end, line: start.line, column: start.column }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lexer::Lexer;
+
+    fn parse(src: &str) -> Result<Vec<Stmt>, Vec<ParseError>> {
+        let tokens = Lexer::run(src.to_string()).expect("source should lex cleanly");
+        Parser::new(tokens).parse()
+    }
+
+    fn only_expr(stmts: &[Stmt]) -> &Expr {
+        match stmts {
+            [Stmt::Expression { expr, .. }] => expr,
+            other => panic!("expected a single expression statement, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn multiplication_binds_tighter_than_addition() {
+        // a + b * c - d  ==  (a + (b * c)) - d
+        let stmts = parse("a + b * c - d;").expect("should parse");
+        let expr = only_expr(&stmts);
+
+        let Expr::Binary { left, op: TokenType::Minus, right, .. } = expr else {
+            panic!("expected the top-level operator to be '-', got {:?}", expr);
+        };
+        assert!(matches!(&**right, Expr::Identifier { name, .. } if name == "d"));
+
+        let Expr::Binary { left: a, op: TokenType::Plus, right: b_times_c, .. } = &**left else {
+            panic!("expected 'a + (b * c)' on the left of '-', got {:?}", left);
+        };
+        assert!(matches!(&**a, Expr::Identifier { name, .. } if name == "a"));
+        assert!(matches!(&**b_times_c, Expr::Binary { op: TokenType::Star, .. }));
+    }
+
+    #[test]
+    fn comparison_binds_looser_than_addition() {
+        // a + b == c  ==  (a + b) == c
+        let stmts = parse("a + b == c;").expect("should parse");
+        let expr = only_expr(&stmts);
+
+        let Expr::Binary { left, op: TokenType::EqualEqual, .. } = expr else {
+            panic!("expected the top-level operator to be '==', got {:?}", expr);
+        };
+        assert!(matches!(&**left, Expr::Binary { op: TokenType::Plus, .. }));
+    }
+
+    #[test]
+    fn unary_minus_and_bang_parse_as_prefix_operators() {
+        let expr = only_expr(&parse("-a;").expect("should parse")).clone();
+        assert!(matches!(expr, Expr::Unary { op: TokenType::Minus, .. }));
+
+        let expr = only_expr(&parse("!a;").expect("should parse")).clone();
+        assert!(matches!(expr, Expr::Unary { op: TokenType::Bang, .. }));
+    }
+
+    #[test]
+    fn function_parses_params_and_return_type() {
+        let stmts = parse("function add(a, b) -> int { return a + b; }").expect("should parse");
+        let [Stmt::Function { name, params, return_type, body, .. }] = stmts.as_slice() else {
+            panic!("expected a single function statement, got {:?}", stmts);
+        };
+
+        assert_eq!(name, "add");
+        assert_eq!(params.iter().map(|p| p.name.as_str()).collect::<Vec<_>>(), vec!["a", "b"]);
+        assert_eq!(return_type.as_deref(), Some("int"));
+        assert!(matches!(body.as_slice(), [Stmt::Return { value: Some(_), .. }]));
+    }
+
+    #[test]
+    fn binding_accepts_both_equal_and_set_spellings() {
+        assert!(parse("let x = 1;").is_ok());
+        assert!(parse("let x := 1;").is_ok());
+    }
+
+    #[test]
+    fn missing_semicolon_is_a_parse_error_not_a_panic() {
+        let errors = parse("let x = 1").expect_err("missing ';' should be a parse error");
+        assert_eq!(errors.len(), 1);
+        assert!(matches!(
+            errors[0].kind,
+            ParseErrorKind::Expected { found: TokenType::Eof, .. }
+        ));
+    }
+
+    #[test]
+    fn missing_closing_paren_is_a_parse_error_not_a_panic() {
+        let errors = parse("let x = (1 + 2;").expect_err("missing ')' should be a parse error");
+        assert_eq!(errors.len(), 1);
+    }
+}